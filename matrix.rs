@@ -1,6 +1,6 @@
 use std::vec;
 use std::fmt;
-use std::num::{Zero, One, one};
+use std::num::{Zero, One, one, ApproxEq};
 
 /// A two-dimensional matrix.
 #[deriving(Clone)]
@@ -47,6 +47,31 @@ impl<'a, T> Iterator<&'a T> for ColumnIterator<'a, T> {
     }
 }
 
+pub struct IndexIterator {
+    priv n: uint,
+    priv m: uint,
+    priv i: uint,
+    priv j: uint,
+}
+
+impl Iterator<(uint, uint)> for IndexIterator {
+    fn next(&mut self) -> Option<(uint, uint)> {
+        if self.i >= self.n || self.m == 0 {
+            return None;
+        }
+
+        let coord = (self.i, self.j);
+
+        self.j += 1;
+        if self.j >= self.m {
+            self.j = 0;
+            self.i += 1;
+        }
+
+        Some(coord)
+    }
+}
+
 // TODO: remove clone bound?
 impl<T: Default+Clone> Mat2<T> {
     /// Create a new (n x m) matrix, using the Default implementation of T
@@ -195,6 +220,26 @@ impl<T> Mat2<T> {
             i: 0
         }
     }
+
+    /// Iterate over every `(i, j)` coordinate pair in the matrix, in row-major order.
+    pub fn indices(&self) -> IndexIterator {
+        IndexIterator { n: self.n, m: self.m, i: 0, j: 0 }
+    }
+}
+
+impl<'a, T> Index<(uint, uint), &'a T> for Mat2<T> {
+    /// Index the matrix with an `(i, j)` coordinate pair. Fails if `i` or `j` are out of bounds.
+    fn index(&'a self, &(i, j): &(uint, uint)) -> &'a T {
+        self.get(i, j)
+    }
+}
+
+impl<'a, T> IndexMut<(uint, uint), &'a mut T> for Mat2<T> {
+    /// Mutably index the matrix with an `(i, j)` coordinate pair. Fails if `i` or `j` are out of
+    /// bounds.
+    fn index_mut(&'a mut self, &(i, j): &(uint, uint)) -> &'a mut T {
+        &mut self.data[i][j]
+    }
 }
 
 impl<T: Mul<T, T>> Mat2<T> {
@@ -206,6 +251,110 @@ impl<T: Mul<T, T>> Mat2<T> {
     }
 }
 
+impl<T: Clone> Mat2<T> {
+    /// Transpose this matrix, returning a new matrix where row `i`, column `j` holds the value
+    /// that was at row `j`, column `i` in the original. An (n x m) matrix becomes (m x n).
+    pub fn transpose(&self) -> Mat2<T> {
+        Mat2::new_with(self.m, self.n, |i, j| self.get(j, i).clone())
+    }
+}
+
+impl<T: Mul<T, T> + Add<T, T> + Zero + Clone> Mat2<T> {
+    /// Multiply this matrix by `other`. Returns `None` if the inner dimensions disagree (this
+    /// matrix's column count must equal `other`'s row count); otherwise returns the product, which
+    /// has this matrix's row count and `other`'s column count, where entry `(i,j)` is the dot
+    /// product of this matrix's row `i` and `other`'s column `j`.
+    pub fn matmul(&self, other: &Mat2<T>) -> Option<Mat2<T>> {
+        if self.m != other.n {
+            return None;
+        }
+
+        Some(Mat2::new_with(self.n, other.m, |i, j| {
+            self.get_row(i).iter().zip(other.column_iter(j))
+                .fold(Zero::zero(), |acc: T, (a, b)| acc + a.clone() * b.clone())
+        }))
+    }
+}
+
+impl<T: Clone> Mat2<T> {
+    /// Return the submatrix formed by deleting row `i` and column `j`. Fails if this matrix isn't
+    /// square or is smaller than 2x2.
+    pub fn minor(&self, i: uint, j: uint) -> Mat2<T> {
+        if self.n != self.m || self.n < 2 {
+            fail!("minor: matrix must be square and at least 2x2");
+        }
+
+        Mat2::new_with(self.n - 1, self.m - 1, |r, c| {
+            let row = if r < i { r } else { r + 1 };
+            let col = if c < j { c } else { c + 1 };
+            self.get(row, col).clone()
+        })
+    }
+}
+
+impl<T: Mul<T, T> + Add<T, T> + Sub<T, T> + Zero + Clone> Mat2<T> {
+    /// Compute the determinant of this matrix by Laplace cofactor expansion along the first row.
+    /// Returns `None` if the matrix isn't square.
+    pub fn determinant(&self) -> Option<T> {
+        if self.n != self.m {
+            return None;
+        }
+
+        Some(self.determinant_unchecked())
+    }
+
+    fn determinant_unchecked(&self) -> T {
+        if self.n == 1 {
+            return self.get(0, 0).clone();
+        }
+
+        let mut det: T = Zero::zero();
+        for j in range(0, self.m) {
+            let term = self.get(0, j).clone() * self.minor(0, j).determinant_unchecked();
+            det = if j % 2 == 0 { det + term } else { det - term };
+        }
+        det
+    }
+}
+
+/// Is `x` close enough to zero to treat as zero? Gauss-Jordan elimination over floating-point
+/// types routinely leaves residuals like `-2.7e-17` where an exact algorithm would leave `0.0`,
+/// so callers that inspect a reduced matrix for zero/pivot structure should use this instead of
+/// `*x == Zero::zero()`.
+fn approx_zero<T: Zero + ApproxEq<T>>(x: &T) -> bool {
+    x.approx_eq(&Zero::zero())
+}
+
+impl<T: Div<T, T> + Mul<T, T> + Add<T, T> + Sub<T, T> + Zero + One + Eq + ApproxEq<T> + Clone> Mat2<T> {
+    /// Compute the inverse of this matrix by forming the augmented matrix `[A | I]` and running
+    /// Gauss-Jordan elimination on it. If the left `n x n` block reduces to the identity (within
+    /// `ApproxEq`'s tolerance, since elimination over floats can leave tiny residuals), the right
+    /// block is `A^-1`; otherwise `A` is singular and `None` is returned. Also returns `None` if
+    /// this matrix isn't square.
+    pub fn inverse(&self) -> Option<Mat2<T>> {
+        if self.n != self.m {
+            return None;
+        }
+
+        let n = self.n;
+        let identity = Mat2::new_with(n, n, |i, j| if i == j { one() } else { Zero::zero() });
+        let mut aug = self.clone();
+        aug.augment(identity);
+        aug.reduce();
+
+        let is_identity = range(0, n).all(|i| range(0, n).all(|j| {
+            let expected = if i == j { one() } else { Zero::zero() };
+            aug.get(i, j).approx_eq(&expected)
+        }));
+
+        if !is_identity {
+            return None;
+        }
+
+        Some(Mat2::new_with(n, n, |i, j| aug.get(i, j + n).clone()))
+    }
+}
+
 impl<T: Eq> Eq for Mat2<T> {
     fn eq(&self, other: &Mat2<T>) -> bool {
         self.data == other.data
@@ -222,34 +371,290 @@ impl<T: Mul<T, T> + Add<T, T> + Clone> Mat2<T> {
     }
 }
 
-impl<T: fmt::Default+Mul<T, T> + Add<T, T> + Div<T, T> + Zero + One + Eq + Clone> Mat2<T> {
+impl<T: Mul<T, T> + Add<T, T> + Sub<T, T> + Div<T, T> + Zero + One + Eq + Clone> Mat2<T> {
     /// Do Gauss-Jordan elimination on this matrix to convert it into Reduced Row-Echelon Form.
     pub fn reduce(&mut self) {
-        // translation of pseudocode at http://linear.ups.edu/html/section-RREF.html
-        let (m, n, mut r) = (self.n, self.m, 0);
-        for j in range(0, n) {
-            let i = r + 1;
-
-            if self.column_iter(j).skip(i).all(|e| *e == Zero::zero()) {
-                debug!("reduce: matrix is zeros in col {} from row {}", j, i);
-                continue
+        let (n, m) = (self.n, self.m);
+        let mut r = 0;
+
+        for j in range(0, m) {
+            if r >= n { break; }
+
+            match range(r, n).find(|&i| *self.get(i, j) != Zero::zero()) {
+                Some(i) => {
+                    self.swap_rows(i, r);
+                    let scale = one::<T>() / self.get(r, j).clone();
+                    self.scale_row(r, scale);
+
+                    for k in range(0, n) {
+                        if k == r { continue; }
+                        let factor = self.get(k, j).clone();
+                        self.add_scaled(r, k, Zero::zero::<T>() - factor);
+                    }
+
+                    r += 1;
+                }
+                None => continue
+            }
+        }
+    }
+}
+
+/// The result of solving a linear system with `Mat2::solve`.
+pub enum Solution<T> {
+    /// The system has exactly one solution.
+    Unique(~[T]),
+    /// The system has no solution (a pivot landed in the augmented column).
+    Inconsistent,
+    /// The system has infinitely many solutions; holds the column indices of the free variables.
+    Underdetermined(~[uint]),
+}
+
+impl<T: Mul<T, T> + Add<T, T> + Sub<T, T> + Div<T, T> + Zero + One + Eq + ApproxEq<T> + Clone> Mat2<T> {
+    /// Solve the linear system `self * x = b` for `x`, where `b` has one entry per row of `self`.
+    /// Augments `self` with `b` as an extra column, reduces the result to RREF, and classifies it
+    /// as a unique solution, an inconsistent system, or an underdetermined system. Returns `None`
+    /// if `b`'s length doesn't match this matrix's row count.
+    pub fn solve(&self, b: &[T]) -> Option<Solution<T>> {
+        let m = self.m;
+        let rhs = match Mat2::from_vec(b.iter().map(|v| ~[v.clone()]).to_owned_vec()) {
+            Some(rhs) => rhs,
+            None => return None
+        };
+        let mut aug = self.clone();
+        if !aug.augment(rhs) {
+            return None;
+        }
+        aug.reduce();
+
+        // the pivot column of each row, among the coefficient columns, if it has one
+        let mut pivots: ~[Option<uint>] = ~[];
+        for row in aug.row_iter() {
+            let mut piv = None;
+            for j in range(0, m) {
+                if !approx_zero(&row[j]) {
+                    piv = Some(j);
+                    break;
+                }
+            }
+            pivots.push(piv);
+        }
+
+        for (i, p) in pivots.iter().enumerate() {
+            if p.is_none() && !approx_zero(aug.get(i, m)) {
+                return Some(Inconsistent);
             }
+        }
+
+        let rank = pivots.iter().filter(|p| p.is_some()).len();
 
-            if i < m+1 {
-                r += 1;
-                self.swap_rows(i, r);
-                let scale_factor = one::<T>() / *self.get(r, j);
-                self.scale_row(r, scale_factor);
-
-                for k in range(1, m) {
-                    debug!("m={}", m);
-                    if (k == r) { break; }
-                    let cur_item = self.get(r, j);
-                    let to_zero = self.get(r, i);
-                    debug!("cur_item={:?}, to_zero={:?}", cur_item, to_zero);
+        if rank == m {
+            let mut x: ~[T] = vec::from_elem(m, Zero::zero());
+            for (i, p) in pivots.iter().enumerate() {
+                match *p {
+                    Some(j) => x[j] = aug.get(i, m).clone(),
+                    None => {}
                 }
             }
+            return Some(Unique(x));
+        }
+
+        let mut free: ~[uint] = ~[];
+        for j in range(0, m) {
+            if pivots.iter().all(|p| *p != Some(j)) {
+                free.push(j);
+            }
         }
+        Some(Underdetermined(free))
+    }
+}
+
+impl<T: Add<T, T> + Clone> Mat2<T> {
+    /// Add two matrices elementwise. Returns `None` if their dimensions don't match.
+    pub fn checked_add(&self, other: &Mat2<T>) -> Option<Mat2<T>> {
+        if self.n != other.n || self.m != other.m {
+            return None;
+        }
+
+        Some(Mat2::new_with(self.n, self.m, |i, j| self.get(i, j).clone() + other.get(i, j).clone()))
+    }
+
+    /// Add `other` into this matrix in place, elementwise. Fails if the dimensions don't match.
+    pub fn add_assign(&mut self, other: &Mat2<T>) {
+        if self.n != other.n || self.m != other.m {
+            fail!("add_assign: dimension mismatch");
+        }
+
+        for i in range(0, self.n) {
+            for j in range(0, self.m) {
+                self.data[i][j] = self.data[i][j].clone() + other.get(i, j).clone();
+            }
+        }
+    }
+}
+
+impl<T: Sub<T, T> + Clone> Mat2<T> {
+    /// Subtract two matrices elementwise. Returns `None` if their dimensions don't match.
+    pub fn checked_sub(&self, other: &Mat2<T>) -> Option<Mat2<T>> {
+        if self.n != other.n || self.m != other.m {
+            return None;
+        }
+
+        Some(Mat2::new_with(self.n, self.m, |i, j| self.get(i, j).clone() - other.get(i, j).clone()))
+    }
+
+    /// Subtract `other` from this matrix in place, elementwise. Fails if the dimensions don't
+    /// match.
+    pub fn sub_assign(&mut self, other: &Mat2<T>) {
+        if self.n != other.n || self.m != other.m {
+            fail!("sub_assign: dimension mismatch");
+        }
+
+        for i in range(0, self.n) {
+            for j in range(0, self.m) {
+                self.data[i][j] = self.data[i][j].clone() - other.get(i, j).clone();
+            }
+        }
+    }
+}
+
+impl<T: Mul<T, T> + Clone> Mat2<T> {
+    /// Multiply every entry of this matrix by the scalar `a`, built on top of `scale_row`.
+    pub fn mul_scalar(&self, a: T) -> Mat2<T> {
+        let mut result = self.clone();
+        for i in range(0, result.n) {
+            result.scale_row(i, a.clone());
+        }
+        result
+    }
+}
+
+impl<T: Add<T, T> + Clone> Add<Mat2<T>, Mat2<T>> for Mat2<T> {
+    /// Add two matrices elementwise. Fails if their dimensions don't match; see `checked_add`
+    /// for a fallible version.
+    fn add(&self, other: &Mat2<T>) -> Mat2<T> {
+        self.checked_add(other).expect("Mat2::add: dimension mismatch")
+    }
+}
+
+impl<T: Sub<T, T> + Clone> Sub<Mat2<T>, Mat2<T>> for Mat2<T> {
+    /// Subtract two matrices elementwise. Fails if their dimensions don't match; see
+    /// `checked_sub` for a fallible version.
+    fn sub(&self, other: &Mat2<T>) -> Mat2<T> {
+        self.checked_sub(other).expect("Mat2::sub: dimension mismatch")
+    }
+}
+
+impl<T: Neg<T> + Clone> Neg<Mat2<T>> for Mat2<T> {
+    /// Negate every entry of this matrix.
+    fn neg(&self) -> Mat2<T> {
+        Mat2::new_with(self.n, self.m, |i, j| -self.get(i, j).clone())
+    }
+}
+
+fn abs<T: Zero + Ord + Sub<T, T> + Clone>(x: &T) -> T {
+    if *x < Zero::zero() {
+        Zero::zero::<T>() - x.clone()
+    } else {
+        x.clone()
+    }
+}
+
+/// The result of LU-decomposing a matrix with partial pivoting: `P * A = L * U`, where `L` is
+/// lower triangular with unit diagonal and `U` is upper triangular.
+pub struct LU<T> {
+    priv l: Mat2<T>,
+    priv u: Mat2<T>,
+    priv permutation: ~[uint],
+    priv odd_swaps: bool,
+}
+
+impl<T> LU<T> {
+    /// The lower triangular factor, with unit diagonal.
+    pub fn l<'a>(&'a self) -> &'a Mat2<T> { &self.l }
+
+    /// The upper triangular factor.
+    pub fn u<'a>(&'a self) -> &'a Mat2<T> { &self.u }
+
+    /// `permutation()[i]` is the index of the original row now in position `i`.
+    pub fn permutation<'a>(&'a self) -> &'a [uint] { self.permutation.as_slice() }
+
+    /// Whether an odd number of row swaps were performed while pivoting. This is the sign flip
+    /// needed when computing a determinant from `U`'s diagonal.
+    pub fn odd_swaps(&self) -> bool { self.odd_swaps }
+}
+
+impl<T: Div<T, T> + Mul<T, T> + Add<T, T> + Sub<T, T> + Zero + One + Eq + Ord + Clone> Mat2<T> {
+    /// Compute an LU decomposition of this matrix with partial pivoting: `P*A = L*U`. At each
+    /// pivot column `k`, the row `p >= k` with the largest `|a[p][k]|` is swapped into position
+    /// `k` (the swap is recorded in the permutation, along with the parity of all swaps), then
+    /// each row below `k` has a multiple of row `k` subtracted from it to clear column `k`; those
+    /// multipliers become `L`'s strictly-lower-triangular entries. Returns `None` if this matrix
+    /// isn't square or is singular.
+    pub fn lu(&self) -> Option<LU<T>> {
+        if self.n != self.m {
+            return None;
+        }
+
+        let n = self.n;
+        let mut u = self.clone();
+        let mut l: Mat2<T> = Mat2::new_with(n, n, |i, j| if i == j { one() } else { Zero::zero() });
+        let mut permutation: ~[uint] = range(0, n).to_owned_vec();
+        let mut odd_swaps = false;
+
+        for k in range(0, n) {
+            let mut p = k;
+            let mut best = abs(u.get(k, k));
+            for i in range(k + 1, n) {
+                let candidate = abs(u.get(i, k));
+                if candidate > best {
+                    best = candidate;
+                    p = i;
+                }
+            }
+
+            if *u.get(p, k) == Zero::zero() {
+                return None;
+            }
+
+            if p != k {
+                u.swap_rows(p, k);
+                permutation.swap(p, k);
+                odd_swaps = !odd_swaps;
+
+                for j in range(0, k) {
+                    let tmp = l.get(p, j).clone();
+                    l.data[p][j] = l.get(k, j).clone();
+                    l.data[k][j] = tmp;
+                }
+            }
+
+            for i in range(k + 1, n) {
+                let factor = u.get(i, k).clone() / u.get(k, k).clone();
+                l.data[i][k] = factor.clone();
+                u.add_scaled(k, i, Zero::zero::<T>() - factor);
+            }
+        }
+
+        Some(LU { l: l, u: u, permutation: permutation, odd_swaps: odd_swaps })
+    }
+
+    /// Compute the determinant as the signed product of `U`'s diagonal from an LU decomposition.
+    /// This is an O(n^3) alternative to the cofactor expansion in `determinant`. Returns `None` if
+    /// this matrix isn't square or is singular.
+    pub fn det_via_lu(&self) -> Option<T> {
+        self.lu().map(|lu| {
+            let mut det: T = one();
+            for i in range(0, self.n) {
+                det = det * lu.u.get(i, i).clone();
+            }
+
+            if lu.odd_swaps {
+                Zero::zero::<T>() - det
+            } else {
+                det
+            }
+        })
     }
 }
 
@@ -304,7 +709,7 @@ impl<T: Zero + One + Ord + Eq> Mat2<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::Mat2;
+    use super::{Mat2, Unique, Inconsistent, Underdetermined};
 
     #[test]
     fn test_cons() {
@@ -478,6 +883,36 @@ mod tests {
         assert_eq!(it.next(), None);
     }
 
+    #[test]
+    fn test_indices() {
+        let x: Mat2<int> = Mat2::new(2, 3);
+        let mut it = x.indices();
+        assert_eq!(it.next(), Some((0, 0)));
+        assert_eq!(it.next(), Some((0, 1)));
+        assert_eq!(it.next(), Some((0, 2)));
+        assert_eq!(it.next(), Some((1, 0)));
+        assert_eq!(it.next(), Some((1, 1)));
+        assert_eq!(it.next(), Some((1, 2)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut x = Mat2::from_vec(
+            ~[
+                ~[1i, 2, 3],
+                ~[4, 5, 6]
+            ]).unwrap();
+        assert_eq!(*x.index(&(0, 1)), 2);
+        assert_eq!(*x.index(&(1, 2)), 6);
+
+        for (i, j) in x.indices() {
+            *x.index_mut(&(i, j)) *= 10;
+        }
+        assert!(x.get_row(0) == &[10, 20, 30]);
+        assert!(x.get_row(1) == &[40, 50, 60]);
+    }
+
     #[test]
     fn test_augment() {
         let mut x = Mat2::from_vec(
@@ -527,6 +962,62 @@ mod tests {
         assert!(x.get_row(1) == &[5, 7, 9]);
     }
 
+    #[test]
+    fn test_add_sub_neg() {
+        let x = Mat2::from_vec(~[~[1i, 2], ~[3, 4]]).unwrap();
+        let y = Mat2::from_vec(~[~[4i, 3], ~[2, 1]]).unwrap();
+
+        let sum = x + y;
+        let mut it = sum.row_iter();
+        assert_eq!(it.next().unwrap(), &[5, 5]);
+        assert_eq!(it.next().unwrap(), &[5, 5]);
+
+        let diff = x - y;
+        let mut it = diff.row_iter();
+        assert_eq!(it.next().unwrap(), &[-3, -1]);
+        assert_eq!(it.next().unwrap(), &[1, 3]);
+
+        let negated = -x;
+        let mut it = negated.row_iter();
+        assert_eq!(it.next().unwrap(), &[-1, -2]);
+        assert_eq!(it.next().unwrap(), &[-3, -4]);
+
+        let z = Mat2::from_vec(~[~[1i, 2, 3]]).unwrap();
+        assert!(x.checked_add(&z).is_none());
+        assert!(x.checked_sub(&z).is_none());
+
+        let mut w = x.clone();
+        w.add_assign(&y);
+        assert!(w.get_row(0) == &[5, 5]);
+        w.sub_assign(&y);
+        assert!(w.get_row(0) == &[1, 2]);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_add_assign_dimension_mismatch() {
+        let mut x = Mat2::from_vec(~[~[1i, 2], ~[3, 4]]).unwrap();
+        let z = Mat2::from_vec(~[~[1i, 2, 3]]).unwrap();
+        x.add_assign(&z);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_sub_assign_dimension_mismatch() {
+        let mut x = Mat2::from_vec(~[~[1i, 2], ~[3, 4]]).unwrap();
+        let z = Mat2::from_vec(~[~[1i, 2, 3]]).unwrap();
+        x.sub_assign(&z);
+    }
+
+    #[test]
+    fn test_mul_scalar() {
+        let x = Mat2::from_vec(~[~[1i, 2], ~[3, 4]]).unwrap();
+        let y = x.mul_scalar(3);
+        let mut it = y.row_iter();
+        assert_eq!(it.next().unwrap(), &[3, 6]);
+        assert_eq!(it.next().unwrap(), &[9, 12]);
+    }
+
     #[test]
     fn test_is_rref() {
         let x = Mat2::from_vec(
@@ -601,6 +1092,157 @@ mod tests {
         assert!(x.is_rref());
     }
 
+    #[test]
+    fn test_transpose() {
+        let x = Mat2::from_vec(
+            ~[
+                ~[1i, 2, 3],
+                ~[4, 5, 6]
+            ]).unwrap();
+        let y = x.transpose();
+        assert_eq!(y.get_dimension(), (2, 3));
+        let mut it = y.row_iter();
+        assert_eq!(it.next().unwrap(), &[1, 4]);
+        assert_eq!(it.next().unwrap(), &[2, 5]);
+        assert_eq!(it.next().unwrap(), &[3, 6]);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_matmul() {
+        let x = Mat2::from_vec(
+            ~[
+                ~[1i, 2, 3],
+                ~[4, 5, 6]
+            ]).unwrap();
+        let y = Mat2::from_vec(
+            ~[
+                ~[7i, 8],
+                ~[9, 10],
+                ~[11, 12]
+            ]).unwrap();
+        let z = x.matmul(&y).unwrap();
+        assert_eq!(z.get_dimension(), (2, 2));
+        let mut it = z.row_iter();
+        assert_eq!(it.next().unwrap(), &[58, 64]);
+        assert_eq!(it.next().unwrap(), &[139, 154]);
+        assert_eq!(it.next(), None);
+
+        assert_eq!(y.matmul(&x).unwrap().get_dimension(), (3, 3));
+        assert!(x.matmul(&x).is_none());
+    }
+
+    #[test]
+    fn test_minor() {
+        let x = Mat2::from_vec(
+            ~[
+                ~[1i, 2, 3],
+                ~[4, 5, 6],
+                ~[7, 8, 9]
+            ]).unwrap();
+        let y = x.minor(1, 1);
+        assert_eq!(y.get_dimension(), (2, 2));
+        let mut it = y.row_iter();
+        assert_eq!(it.next().unwrap(), &[1, 3]);
+        assert_eq!(it.next().unwrap(), &[7, 9]);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_minor_too_small() {
+        let x: Mat2<int> = Mat2::new(1, 1);
+        x.minor(0, 0);
+    }
+
+    #[test]
+    fn test_determinant() {
+        let x = Mat2::from_vec(~[~[5i]]).unwrap();
+        assert_eq!(x.determinant(), Some(5));
+
+        let x = Mat2::from_vec(
+            ~[
+                ~[1i, 2],
+                ~[3, 4]
+            ]).unwrap();
+        assert_eq!(x.determinant(), Some(-2));
+
+        let x = Mat2::from_vec(
+            ~[
+                ~[6i, 1, 1],
+                ~[4, -2, 5],
+                ~[2, 8, 7]
+            ]).unwrap();
+        assert_eq!(x.determinant(), Some(-306));
+
+        let x = Mat2::from_vec(
+            ~[
+                ~[1i, 2, 3],
+                ~[4, 5, 6]
+            ]).unwrap();
+        assert_eq!(x.determinant(), None);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let x = Mat2::from_vec(
+            ~[
+                ~[4f64, 7.0],
+                ~[2.0, 6.0]
+            ]).unwrap();
+        let y = x.inverse().unwrap();
+        let expected = [[0.6f64, -0.7], [-0.2, 0.4]];
+        for (row, expected_row) in y.row_iter().zip(expected.iter()) {
+            for (v, e) in row.iter().zip(expected_row.iter()) {
+                let diff = *v - *e;
+                assert!(diff < 1e-9 && diff > -1e-9);
+            }
+        }
+
+        let identity = x.matmul(&y).unwrap();
+        for (row, expected_row) in identity.row_iter().zip([[1f64, 0.0], [0.0, 1.0]].iter()) {
+            for (v, e) in row.iter().zip(expected_row.iter()) {
+                let diff = *v - *e;
+                assert!(diff < 1e-9 && diff > -1e-9);
+            }
+        }
+
+        // singular
+        let s = Mat2::from_vec(
+            ~[
+                ~[1f64, 2.0],
+                ~[2.0, 4.0]
+            ]).unwrap();
+        assert!(s.inverse().is_none());
+
+        // non-square
+        let r = Mat2::from_vec(~[~[1f64, 2.0, 3.0]]).unwrap();
+        assert!(r.inverse().is_none());
+    }
+
+    #[test]
+    fn test_inverse_with_float_residue() {
+        // These are genuinely invertible, but Gauss-Jordan elimination over f64 leaves tiny
+        // nonzero residuals (e.g. -2.7e-17) where an exact algorithm would leave 0.0; exact
+        // equality against the identity used to reject them as singular.
+        let matrices = [
+            Mat2::from_vec(~[~[1.3f64, 0.7], ~[-0.8, 0.4]]).unwrap(),
+            Mat2::from_vec(~[~[-1.9f64, 0.2], ~[1.8, -0.5]]).unwrap(),
+        ];
+
+        for a in matrices.iter() {
+            let a_inv = a.inverse().expect("genuinely invertible matrix rejected as singular");
+            let identity = a.matmul(&a_inv).unwrap();
+            for (i, row) in identity.row_iter().enumerate() {
+                for (j, v) in row.iter().enumerate() {
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    let diff = *v - expected;
+                    assert!(diff < 1e-9 && diff > -1e-9);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_reduce() {
         let mut x = Mat2::from_vec(
@@ -609,15 +1251,97 @@ mod tests {
                 ~[1.0, 5.0, 6.0],
                 ~[1.0, 8.0, 9.0]
             ]).unwrap();
-        println!("{}", x);
         x.reduce();
+        assert!(x.is_rref());
+        let mut it = x.row_iter();
+        assert_eq!(it.next().unwrap(), &[1.0, 0.0, 1.0]);
+        assert_eq!(it.next().unwrap(), &[0.0, 1.0, 1.0]);
+        assert_eq!(it.next().unwrap(), &[0.0, 0.0, 0.0]);
+        assert_eq!(it.next(), None);
+
         let mut x = Mat2::from_vec(
             ~[
                 ~[1f64, 0.0, 3.0],
                 ~[1.0, 0.0, 6.0],
                 ~[1.0, 0.0, 9.0]
             ]).unwrap();
-        println!("{}", x);
         x.reduce();
+        assert!(x.is_rref());
+        let mut it = x.row_iter();
+        assert_eq!(it.next().unwrap(), &[1.0, 0.0, 0.0]);
+        assert_eq!(it.next().unwrap(), &[0.0, 0.0, 1.0]);
+        assert_eq!(it.next().unwrap(), &[0.0, 0.0, 0.0]);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = Mat2::from_vec(~[~[2f64, 1.0], ~[1.0, 1.0]]).unwrap();
+        match a.solve([3.0, 2.0]) {
+            Some(Unique(x)) => assert_eq!(x, ~[1.0, 1.0]),
+            _ => fail!("expected a unique solution")
+        }
+
+        let a = Mat2::from_vec(~[~[1f64, 1.0], ~[1.0, 1.0]]).unwrap();
+        match a.solve([1.0, 2.0]) {
+            Some(Inconsistent) => {},
+            _ => fail!("expected an inconsistent system")
+        }
+
+        let a = Mat2::from_vec(~[~[1f64, 1.0], ~[2.0, 2.0]]).unwrap();
+        match a.solve([2.0, 4.0]) {
+            Some(Underdetermined(free)) => assert_eq!(free, ~[1]),
+            _ => fail!("expected an underdetermined system")
+        }
+
+        // b's length doesn't match the row count
+        let a = Mat2::from_vec(~[~[1f64, 1.0], ~[2.0, 2.0]]).unwrap();
+        assert!(a.solve([1.0]).is_none());
+    }
+
+    #[test]
+    fn test_solve_with_float_residue() {
+        // Elimination over these coefficients leaves tiny nonzero residuals in entries that are
+        // mathematically zero; an exact `!= Zero::zero()` pivot test would misclassify the
+        // (genuinely unique) solution below as inconsistent or underdetermined.
+        let a = Mat2::from_vec(~[~[1.3f64, 0.7], ~[-0.8, 0.4]]).unwrap();
+        match a.solve([2.0, -0.4]) {
+            Some(Unique(x)) => {
+                assert!(x.len() == 2);
+                for v in x.iter() {
+                    let diff = *v - 1.0;
+                    assert!(diff < 1e-9 && diff > -1e-9);
+                }
+            }
+            _ => fail!("expected a unique solution")
+        }
+    }
+
+    #[test]
+    fn test_lu() {
+        let a = Mat2::from_vec(~[~[4f64, 3.0], ~[6.0, 3.0]]).unwrap();
+        let lu = a.lu().unwrap();
+        assert_eq!(lu.permutation(), &[1, 0]);
+        assert!(lu.odd_swaps());
+
+        let mut it = lu.u().row_iter();
+        assert_eq!(it.next().unwrap(), &[6.0, 3.0]);
+        assert_eq!(it.next().unwrap(), &[0.0, 1.0]);
+
+        let mut it = lu.l().row_iter();
+        assert_eq!(it.next().unwrap(), &[1.0, 0.0]);
+        let row = it.next().unwrap();
+        let diff = row[0] - (2.0 / 3.0);
+        assert!(diff < 1e-9 && diff > -1e-9);
+        assert_eq!(row[1], 1.0);
+
+        assert_eq!(a.det_via_lu(), Some(-6.0));
+
+        let singular = Mat2::from_vec(~[~[1f64, 2.0], ~[2.0, 4.0]]).unwrap();
+        assert!(singular.lu().is_none());
+        assert_eq!(singular.det_via_lu(), None);
+
+        let non_square = Mat2::from_vec(~[~[1f64, 2.0, 3.0]]).unwrap();
+        assert!(non_square.lu().is_none());
     }
 }